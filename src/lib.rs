@@ -1,7 +1,9 @@
 #![allow(clippy::doc_markdown, clippy::missing_errors_doc)]
 
+mod native;
 #[cfg(test)]
 mod test;
+mod zone;
 
 use std::{process::Command, str};
 
@@ -74,33 +76,118 @@ pub enum Error {
     #[error("Datetime overflow")]
     DatetimeOverflow,
 
+    /// Failed to locate or read the system tz database file for a named zone.
+    #[error("Unable to read tz database file for zone '{0}': {1}")]
+    ZoneFile(String, std::io::Error),
+
+    /// Failed to parse a tz database file, or find the local time type in effect, for a
+    /// named zone.
+    #[error("Unable to resolve tz database zone '{0}': {1}")]
+    ZoneData(String, String),
+
+    /// The zone name was not a plausible IANA zone name (e.g. it contained a `..`
+    /// component or characters outside `[A-Za-z0-9_+-/]`).
+    #[error("Invalid tz database zone name: {0}")]
+    InvalidZoneName(String),
+
     /// The global offset is not initialized.
     #[error("The global offset is not initialized.")]
     Uninitialized,
+
+    /// `OffsetKind::Unknown` represents RFC 3339's `-00:00` marker, which is only
+    /// meaningful paired with a UTC offset.
+    #[error("OffsetKind::Unknown may only be paired with UtcOffset::UTC, got {0:?}")]
+    InvalidOffsetKindPairing(UtcOffset),
+}
+
+/// Distinguishes a genuinely-determined UTC offset from a fallback where the real
+/// local offset could not be determined.
+///
+/// RFC 3339 / RFC 2822 treat `-00:00` as semantically distinct from `+00:00`: it means
+/// "the time is in UTC but the local offset is unknown," as opposed to a local offset
+/// that is actually, deliberately UTC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OffsetKind {
+    /// The offset was actually determined, whether by the caller or by the system.
+    Known,
+    /// The offset could not be determined, so we're reporting UTC but flagging it as
+    /// unknown per the RFC 3339 `-00:00` convention.
+    Unknown,
+}
+
+/// The process-wide offset configuration: either a fixed offset (with its [`OffsetKind`])
+/// or a named IANA zone that gets resolved fresh on every lookup, so DST transitions are
+/// honored instead of a single offset being cached forever.
+#[derive(Debug, Clone)]
+enum GlobalOffset {
+    Fixed(UtcOffset, OffsetKind),
+    Zone(String),
 }
 
-static OFFSET: OnceCell<RwLock<UtcOffset>> = OnceCell::new();
+static OFFSET: OnceCell<RwLock<GlobalOffset>> = OnceCell::new();
 const TIME_FORMAT: &[FormatItem<'static>] = format_description!(
     "[year]-[month]-[day]T[hour]:[minute]:[second][offset_hour sign:mandatory]:[offset_second]"
 );
 const PARSE_FORMAT: &[FormatItem<'static>] =
     format_description!("[offset_hour][optional [:]][offset_minute]");
+const PARSE_FORMAT_HOUR_ONLY: &[FormatItem<'static>] = format_description!("[offset_hour]");
+const PARSE_FORMAT_WITH_SECONDS: &[FormatItem<'static>] = format_description!(
+    "[offset_hour][optional [:]][offset_minute][optional [:]][offset_second]"
+);
+/// Patterns tried, in order, when parsing a caller-supplied offset string. Ordered from
+/// most to least specific so that a full match is always preferred over a partial one.
+const OFFSET_PARSE_FORMATS: &[&[FormatItem<'static>]] = &[
+    PARSE_FORMAT_WITH_SECONDS,
+    PARSE_FORMAT,
+    PARSE_FORMAT_HOUR_ONLY,
+];
 
 /// Returns the global offset value if it is initialized, otherwise it
 /// returns an error. Unlike the `try_set_` functions, this waits for a read lock.
 pub fn get_global_offset() -> Result<UtcOffset> {
-    if let Some(o) = OFFSET.get() {
-        Ok(o.read().clone())
-    } else {
-        Err(Error::Uninitialized)
+    get_global_offset_with_kind().map(|(o, _)| o)
+}
+
+/// Returns the global offset value along with its [`OffsetKind`] if it is initialized,
+/// otherwise it returns an error. Unlike the `try_set_` functions, this waits for a read lock.
+///
+/// If the global offset is configured from a named zone, this resolves the offset in
+/// effect right now rather than returning a value cached at configuration time.
+pub fn get_global_offset_with_kind() -> Result<(UtcOffset, OffsetKind)> {
+    let Some(lock) = OFFSET.get() else {
+        return Err(Error::Uninitialized);
+    };
+    match &*lock.read() {
+        GlobalOffset::Fixed(o, kind) => Ok((*o, *kind)),
+        GlobalOffset::Zone(name) => Ok((zone::offset_for_zone_now(name)?, OffsetKind::Known)),
     }
 }
+
 /// Attempts to set the global offset, returning an error if the
-/// write lock cannot be obtained.
+/// write lock cannot be obtained. The offset is recorded as [`OffsetKind::Known`].
 pub fn try_set_global_offset(o: UtcOffset) -> Result<()> {
-    let o_ref = OFFSET.get_or_init(|| RwLock::new(o));
+    try_set_global_offset_with_kind(o, OffsetKind::Known)
+}
+
+/// Attempts to set the global offset along with its [`OffsetKind`], returning an error
+/// if the write lock cannot be obtained.
+///
+/// # Errors
+/// [`OffsetKind::Unknown`] represents RFC 3339's `-00:00` marker and is only meaningful
+/// paired with [`UtcOffset::UTC`]; pairing it with any other offset returns
+/// `Error::InvalidOffsetKindPairing` rather than silently producing a `+HH:MM` timestamp
+/// with no unknown-offset marker.
+pub fn try_set_global_offset_with_kind(o: UtcOffset, kind: OffsetKind) -> Result<()> {
+    if kind == OffsetKind::Unknown && o != UtcOffset::UTC {
+        return Err(Error::InvalidOffsetKindPairing(o));
+    }
+    set_global_offset(GlobalOffset::Fixed(o, kind))
+}
+
+fn set_global_offset(g: GlobalOffset) -> Result<()> {
+    let o_ref = OFFSET.get_or_init(|| RwLock::new(g.clone()));
     if let Some(mut o_lock) = o_ref.try_write() {
-        *o_lock = o;
+        *o_lock = g;
         Ok(())
     } else {
         Err(Error::WriteLock)
@@ -108,18 +195,43 @@ pub fn try_set_global_offset(o: UtcOffset) -> Result<()> {
 }
 
 /// Sets a static UTC offset, from an input string, to use with future calls to
-/// `get_local_timestamp_rfc3339`. The format should be [+/-]HHMM.
+/// `get_local_timestamp_rfc3339`. Accepts the permissive range of ISO 8601 / RFC 3339
+/// offset spellings, not just [+/-]HHMM. `-00:00` (and its bare/seconds/hour-only
+/// spellings) is treated as the RFC 3339 "offset unknown" marker, round-tripping back to
+/// [`OffsetKind::Unknown`] rather than a plain UTC offset.
 ///
 /// # Arguments
-/// * input - The UTC offset as a string. Example values are: +0900, -0930,
-///   1000, +09:00, -09:30, 10:00
+/// * input - The UTC offset as a string. Example values are: Z, z, +0900, -0930,
+///   1000, +09:00, -09:30, 10:00, +09, -09:30:15, -00:00
 ///
 /// # Error
 /// If we fail to parse the input offset string we'll return an `Error::InvalidOffsetString`.
 pub fn try_set_global_offset_from_str(input: &str) -> Result<()> {
     let trimmed = trim_new_lines(input);
-    let o = UtcOffset::parse(trimmed, &PARSE_FORMAT).map_err(|_| Error::InvalidOffsetString)?;
-    try_set_global_offset(o)
+    let (o, kind) = parse_offset_str(trimmed)?;
+    try_set_global_offset_with_kind(o, kind)
+}
+
+/// Parses the permissive ISO 8601 / RFC 3339 offset spellings: a literal `Z`/`z` for UTC,
+/// or `[+/-]HH` with an optional `:`-separated `MM` and `SS`. A negative-signed zero
+/// offset (e.g. `-00:00`) is parsed as [`OffsetKind::Unknown`] rather than a known UTC
+/// offset, per RFC 3339.
+fn parse_offset_str(trimmed: &str) -> Result<(UtcOffset, OffsetKind)> {
+    if trimmed.eq_ignore_ascii_case("z") {
+        return Ok((UtcOffset::UTC, OffsetKind::Known));
+    }
+
+    let o = OFFSET_PARSE_FORMATS
+        .iter()
+        .find_map(|fmt| UtcOffset::parse(trimmed, fmt).ok())
+        .ok_or(Error::InvalidOffsetString)?;
+
+    let kind = if o == UtcOffset::UTC && trimmed.trim_start().starts_with('-') {
+        OffsetKind::Unknown
+    } else {
+        OffsetKind::Known
+    };
+    Ok((o, kind))
 }
 
 /// Sets a static UTC offset to use with future calls to
@@ -139,6 +251,57 @@ pub fn try_set_global_offset_from_pair(offset_hours: i8, offset_minutes: i8) ->
     try_set_global_offset(o)
 }
 
+/// Sets the global offset to use with future calls to `get_local_timestamp_rfc3339`,
+/// resolved from a named IANA timezone (e.g. `America/New_York`, `Europe/Berlin`) via the
+/// system tz database, rather than a fixed numeric offset.
+///
+/// Unlike `try_set_global_offset_from_pair`/`_from_str`, the offset is not cached as a
+/// fixed value: it is re-resolved from the zone's transition table on every lookup, so
+/// `get_local_timestamp_rfc3339` stays correct across DST boundaries instead of drifting
+/// once the process outlives a transition.
+///
+/// # Arguments
+/// * name - An IANA zone name, e.g. `America/New_York`.
+///
+/// # Errors
+/// If the named zone cannot be found under the system tz database, or its data cannot be
+/// parsed, an error is returned.
+pub fn try_set_global_offset_from_zone(name: &str) -> Result<()> {
+    // Resolve once up front purely to validate the zone before caching it.
+    zone::offset_for_zone_now(name)?;
+    set_global_offset(GlobalOffset::Zone(name.to_string()))
+}
+
+/// Returns the UTC offset in effect for `instant` in the named IANA `zone`, selecting the
+/// correct `LocalTimeType` by consulting the zone's transition table for that specific
+/// moment rather than "now".
+///
+/// # Errors
+/// If the named zone cannot be found under the system tz database, or its data cannot be
+/// parsed, an error is returned.
+pub fn local_offset_at(zone: &str, instant: OffsetDateTime) -> Result<UtcOffset> {
+    zone::offset_for_zone_at(zone, instant.unix_timestamp())
+}
+
+/// Gets a timestamp string for `instant`, formatted using the UTC offset in effect for
+/// that instant in the named IANA `zone`. Building on `local_offset_at`, this stays
+/// correct for historical and future timestamps across DST boundaries, unlike the cached
+/// fixed-offset lookups above.
+///
+/// # Returns
+/// Returns a `Result` timestamp in the following format or the error encountered during its construction.
+/// ```text
+/// [year]-[month]-[day]T[hour]:[minute]:[second][offset_hour sign:mandatory]:[offset_second]
+/// ```
+pub fn get_local_timestamp_from_zone_at(zone: &str, instant: OffsetDateTime) -> Result<String> {
+    let offset = local_offset_at(zone, instant)?;
+    let in_zone = instant
+        .checked_to_offset(offset)
+        .ok_or(Error::DatetimeOverflow)?;
+    let formatted = in_zone.format(&TIME_FORMAT)?;
+    Ok(formatted)
+}
+
 /// Gets a timestamp string using in either the local offset or +00:00
 ///
 /// # Returns
@@ -150,11 +313,13 @@ pub fn try_set_global_offset_from_pair(offset_hours: i8, offset_minutes: i8) ->
 /// The timezone will be in the local offset IF any of the following succeed:
 ///     1.) set_global_offset is called.
 ///     2.) `time::UtcOffset::current_local_offset()` works
-///     3.) The library is able to query the timezone using system commands.
-/// If none succeed, we default to UTC.
+///     3.) The library is able to query the local offset via a native OS syscall.
+///     4.) The library is able to query the timezone using system commands.
+/// If none succeed, we emit `-00:00` instead of `+00:00`, per RFC 3339, to flag that the
+/// offset is an unknown fallback rather than a genuinely-determined UTC offset.
 pub fn get_local_timestamp_rfc3339() -> Result<(String, Errors)> {
-    let (offset, errs) = get_utc_offset();
-    let res = get_local_timestamp_from_offset_rfc3339(offset)?;
+    let (offset, kind, errs) = get_utc_offset_with_kind();
+    let res = format_local_timestamp(offset, kind)?;
     Ok((res, errs))
 }
 
@@ -187,26 +352,53 @@ pub fn get_local_timestamp_from_offset_rfc3339(utc_offset: UtcOffset) -> Result<
     Ok(formatted)
 }
 
+/// Formats the current time at `utc_offset`, rewriting the offset to `-00:00` when `kind` is
+/// [`OffsetKind::Unknown`] so callers can tell a real UTC timestamp from a best-effort one.
+fn format_local_timestamp(utc_offset: UtcOffset, kind: OffsetKind) -> Result<String> {
+    let formatted = get_local_timestamp_from_offset_rfc3339(utc_offset)?;
+    if kind == OffsetKind::Unknown {
+        Ok(formatted.replacen("+00:00", "-00:00", 1))
+    } else {
+        Ok(formatted)
+    }
+}
+
 /// Do whatever it takes to get a utc offset and cache it.
-/// Worst case scenario we just assume UTC time.
+/// Worst case scenario we just assume UTC time, flagged as [`OffsetKind::Unknown`].
 pub fn get_utc_offset() -> (UtcOffset, Errors) {
+    let (o, _kind, errs) = get_utc_offset_with_kind();
+    (o, errs)
+}
+
+/// Do whatever it takes to get a utc offset and cache it, also reporting whether the
+/// offset was actually determined or is an [`OffsetKind::Unknown`] fallback to UTC.
+pub fn get_utc_offset_with_kind() -> (UtcOffset, OffsetKind, Errors) {
     let mut errs = Errors::new();
-    if let Ok(o) = get_global_offset() {
-        return (o, errs);
+    match get_global_offset_with_kind() {
+        Ok((o, kind)) => return (o, kind, errs),
+        // Only an uninitialized cache should fall through to `construct_offset` and be
+        // cached as a `Fixed` value below. Any other error (e.g. a configured
+        // `GlobalOffset::Zone` that failed to re-resolve this time around) must not
+        // clobber the caller's stored configuration, so report it best-effort instead.
+        Err(Error::Uninitialized) => {}
+        Err(e) => {
+            errs.push(e);
+            return (UtcOffset::UTC, OffsetKind::Unknown, errs);
+        }
     }
 
-    let o = match construct_offset() {
-        Ok(o) => o,
+    let (o, kind) = match construct_offset() {
+        Ok(o) => (o, OffsetKind::Known),
         Err(e) => {
             errs.push(e);
-            UtcOffset::UTC
+            (UtcOffset::UTC, OffsetKind::Unknown)
         }
     };
 
-    if let Err(e) = try_set_global_offset(o) {
+    if let Err(e) = try_set_global_offset_with_kind(o, kind) {
         errs.push(e)
     }
-    (o, errs)
+    (o, kind, errs)
 }
 
 fn parse_cmd_output(stdout: &[u8], formatter: &[FormatItem<'static>]) -> Result<UtcOffset> {
@@ -249,7 +441,24 @@ fn from_offset_pair(offset_hours: i8, offset_minutes: i8) -> Result<UtcOffset> {
     Ok(UtcOffset::from_hms(offset_hours, offset_minutes, 0)?)
 }
 
+/// Attempts to obtain the local UTC offset via a native OS syscall, without spawning a
+/// subprocess. Returns `None` if the platform-specific lookup did not yield an offset, in
+/// which case the caller should fall back to `offset_from_process`.
+fn offset_from_syscall() -> Option<UtcOffset> {
+    // SAFETY: `native::local_offset_seconds` itself checks that the process is
+    // single-threaded before touching `tzset`/`localtime_r`, satisfying the same
+    // single-thread contract `UtcOffset::current_local_offset` relies on.
+    let secs = unsafe { native::local_offset_seconds() }?;
+    UtcOffset::from_whole_seconds(secs).ok()
+}
+
 /// Construct an offset.
 fn construct_offset() -> Result<UtcOffset> {
-    UtcOffset::current_local_offset().or_else(|_| offset_from_process())
+    if let Ok(o) = UtcOffset::current_local_offset() {
+        return Ok(o);
+    }
+    if let Some(o) = offset_from_syscall() {
+        return Ok(o);
+    }
+    offset_from_process()
 }
@@ -0,0 +1,112 @@
+//! Native, subprocess-free local-offset lookup.
+//!
+//! `offset_from_process` in the parent module shells out to `date`/`Get-Date`, which is
+//! slow, locale-dependent, and unavailable in sandboxes with no `PATH`. This module asks
+//! the OS directly instead.
+
+#[cfg(unix)]
+mod imp {
+    use std::mem::MaybeUninit;
+
+    /// Returns the local UTC offset, in whole seconds, via `tzset`/`localtime_r`, or
+    /// `None` if the underlying syscalls fail.
+    ///
+    /// # Safety
+    /// `tzset`/`localtime_r` read the `TZ` environment variable. Like
+    /// `time::util::local_offset`'s escape hatch for `UtcOffset::current_local_offset`,
+    /// this is only sound to call while no other thread may be mutating the environment.
+    pub(super) unsafe fn local_offset_seconds() -> Option<i32> {
+        libc::tzset();
+
+        let now = libc::time(std::ptr::null_mut());
+        if now == -1 {
+            return None;
+        }
+
+        let mut tm = MaybeUninit::<libc::tm>::uninit();
+        if libc::localtime_r(&now, tm.as_mut_ptr()).is_null() {
+            return None;
+        }
+
+        Some(tm.assume_init().tm_gmtoff as i32)
+    }
+}
+
+#[cfg(windows)]
+mod imp {
+    use std::mem::MaybeUninit;
+
+    use windows_sys::Win32::Foundation::SYSTEMTIME;
+    use windows_sys::Win32::System::SystemInformation::GetSystemTime;
+    use windows_sys::Win32::System::Time::{
+        GetTimeZoneInformationForYear, SystemTimeToFileTime, SystemTimeToTzSpecificLocalTime,
+        FILETIME, TIME_ZONE_INFORMATION,
+    };
+
+    /// Returns the local UTC offset, in whole seconds, via the Win32 timezone API, or
+    /// `None` if the underlying calls fail.
+    pub(super) unsafe fn local_offset_seconds() -> Option<i32> {
+        let mut utc: SYSTEMTIME = std::mem::zeroed();
+        GetSystemTime(&mut utc);
+
+        let mut tzi: TIME_ZONE_INFORMATION = std::mem::zeroed();
+        if GetTimeZoneInformationForYear(utc.wYear, std::ptr::null_mut(), &mut tzi) == 0 {
+            return None;
+        }
+
+        let mut local: SYSTEMTIME = std::mem::zeroed();
+        if SystemTimeToTzSpecificLocalTime(&tzi, &utc, &mut local) == 0 {
+            return None;
+        }
+
+        let mut utc_ft = MaybeUninit::<FILETIME>::uninit();
+        let mut local_ft = MaybeUninit::<FILETIME>::uninit();
+        if SystemTimeToFileTime(&utc, utc_ft.as_mut_ptr()) == 0
+            || SystemTimeToFileTime(&local, local_ft.as_mut_ptr()) == 0
+        {
+            return None;
+        }
+        let to_100ns = |ft: FILETIME| ((ft.dwHighDateTime as i64) << 32) | ft.dwLowDateTime as i64;
+        let diff_100ns = to_100ns(local_ft.assume_init()) - to_100ns(utc_ft.assume_init());
+
+        Some((diff_100ns / 10_000_000) as i32)
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+mod imp {
+    pub(super) unsafe fn local_offset_seconds() -> Option<i32> {
+        None
+    }
+}
+
+/// Returns whether the current process appears to be single-threaded, gating the Unix
+/// syscall path behind the same check `time`'s `local-offset` feature performs (via the
+/// `num_threads` crate) before calling `tzset`/`localtime_r` — those read the `TZ`
+/// environment variable and are unsound if another thread could be mutating it
+/// concurrently. Windows' backend doesn't depend on the environment, so it's always safe.
+#[cfg(unix)]
+fn is_single_threaded() -> bool {
+    matches!(num_threads::num_threads(), Some(n) if n.get() == 1)
+}
+
+#[cfg(not(unix))]
+fn is_single_threaded() -> bool {
+    true
+}
+
+/// Attempts to obtain the local UTC offset directly from the OS, without spawning a
+/// subprocess. Returns `None` if the platform-specific lookup did not yield an offset, or
+/// if the single-thread safety check fails, in which case the caller should fall back to
+/// `offset_from_process`.
+///
+/// # Safety
+/// `is_single_threaded` is checked before calling into the platform implementation, so
+/// the Unix path only runs when it is actually sound to do so, per the same contract
+/// `time`'s local-offset support relies on.
+pub(super) unsafe fn local_offset_seconds() -> Option<i32> {
+    if !is_single_threaded() {
+        return None;
+    }
+    imp::local_offset_seconds()
+}
@@ -1,12 +1,24 @@
+use std::sync::Mutex;
+
 use once_cell::sync::Lazy;
 use regex::Regex;
 
-use crate::{get_local_timestamp_rfc3339, offset_from_process, try_set_global_offset_from_pair};
+use time::UtcOffset;
+
+use crate::{
+    get_global_offset_with_kind, get_local_timestamp_from_zone_at, get_local_timestamp_rfc3339,
+    local_offset_at, offset_from_process, offset_from_syscall, try_set_global_offset_from_pair,
+    try_set_global_offset_from_str, try_set_global_offset_from_zone, OffsetKind,
+};
 
 static TIME_FORMAT_REGEX: Lazy<Regex> = Lazy::new(|| {
     Regex::new("\\d{4}-\\d{2}-\\d{2}T\\d{2}:\\d{2}:\\d{2}[+|-]\\d{2}:\\d{2}").unwrap()
 });
 
+/// Serializes tests that set-then-read the process-wide `OFFSET` global, which `cargo
+/// test`'s default multi-threaded runner would otherwise race.
+static OFFSET_TEST_LOCK: Lazy<Mutex<()>> = Lazy::new(|| Mutex::new(()));
+
 macro_rules! test_is_ok {
     ($offset_hr:expr, $offset_min:expr, $exp_ts_offset:expr) => {
         assert!(try_set_global_offset_from_pair($offset_hr, $offset_min).is_ok());
@@ -21,6 +33,7 @@ macro_rules! test_is_ok {
 
 #[test]
 fn offset_tests() {
+    let _guard = OFFSET_TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
     test_is_ok!(-8, 0, "-08:00");
     test_is_ok!(6, 0, "+06:00");
     test_is_ok!(0, 0, "+00:00");
@@ -35,3 +48,95 @@ fn get_offset_from_proc_test() {
     let res = offset_from_process();
     assert!(res.is_ok());
 }
+
+#[test]
+fn native_syscall_offset_test() {
+    let res = offset_from_syscall();
+    // On Unix the syscall path only runs while the process is single-threaded (a
+    // multi-threaded test run usually is not), and yields `None` otherwise so that
+    // `construct_offset` falls back to spawning a subprocess. Windows has no such gate.
+    #[cfg(unix)]
+    assert_eq!(
+        res.is_some(),
+        matches!(num_threads::num_threads(), Some(n) if n.get() == 1),
+        "res: {:#?}",
+        res
+    );
+    #[cfg(windows)]
+    assert!(res.is_some(), "res: {:#?}", res);
+    #[cfg(not(any(unix, windows)))]
+    assert!(res.is_none(), "res: {:#?}", res);
+}
+
+#[test]
+fn zone_offset_test() {
+    let _guard = OFFSET_TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    assert!(try_set_global_offset_from_zone("UTC").is_ok());
+    let (offset, kind) = get_global_offset_with_kind().unwrap();
+    assert_eq!(offset, UtcOffset::UTC);
+    assert_eq!(kind, OffsetKind::Known);
+
+    assert!(try_set_global_offset_from_zone("Not_A_Real_Zone/Nowhere").is_err());
+    assert!(try_set_global_offset_from_zone("../../../etc/shadow").is_err());
+    assert!(try_set_global_offset_from_zone("America/../../etc/shadow").is_err());
+}
+
+#[test]
+fn dst_transition_offset_test() {
+    use time::macros::datetime;
+
+    // US Eastern spring-forward: 2023-03-12 02:00 local became 03:00 local, i.e.
+    // 2023-03-12 07:00 UTC is the instant the offset flips from -05:00 to -04:00.
+    let before = datetime!(2023-03-12 06:59:00 UTC);
+    let after = datetime!(2023-03-12 07:01:00 UTC);
+
+    let offset_before = local_offset_at("America/New_York", before).unwrap();
+    let offset_after = local_offset_at("America/New_York", after).unwrap();
+    assert_eq!(offset_before.whole_hours(), -5, "offset: {:?}", offset_before);
+    assert_eq!(offset_after.whole_hours(), -4, "offset: {:?}", offset_after);
+
+    let ts_before = get_local_timestamp_from_zone_at("America/New_York", before).unwrap();
+    let ts_after = get_local_timestamp_from_zone_at("America/New_York", after).unwrap();
+    assert!(ts_before.ends_with("-05:00"), "ts: {}", ts_before);
+    assert!(ts_after.ends_with("-04:00"), "ts: {}", ts_after);
+}
+
+#[test]
+fn permissive_offset_str_test() {
+    let _guard = OFFSET_TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    assert!(try_set_global_offset_from_str("Z").is_ok());
+    let (ts, _) = get_local_timestamp_rfc3339().unwrap();
+    assert!(ts.ends_with("+00:00"), "ts: {}", ts);
+
+    assert!(try_set_global_offset_from_str("z").is_ok());
+    let (ts, _) = get_local_timestamp_rfc3339().unwrap();
+    assert!(ts.ends_with("+00:00"), "ts: {}", ts);
+
+    assert!(try_set_global_offset_from_str("+09").is_ok());
+    let (ts, _) = get_local_timestamp_rfc3339().unwrap();
+    assert!(ts.ends_with("+09:00"), "ts: {}", ts);
+
+    assert!(try_set_global_offset_from_str("-05:30:00").is_ok());
+    let (ts, _) = get_local_timestamp_rfc3339().unwrap();
+    assert!(ts.ends_with("-05:30"), "ts: {}", ts);
+
+    assert!(try_set_global_offset_from_str("not-an-offset").is_err());
+}
+
+#[test]
+fn offset_unknown_marker_round_trips() {
+    let _guard = OFFSET_TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    assert!(try_set_global_offset_from_str("-00:00").is_ok());
+    let (offset, kind) = get_global_offset_with_kind().unwrap();
+    assert_eq!(offset, UtcOffset::UTC);
+    assert_eq!(kind, OffsetKind::Unknown);
+    let (ts, _) = get_local_timestamp_rfc3339().unwrap();
+    assert!(ts.ends_with("-00:00"), "ts: {}", ts);
+
+    assert!(try_set_global_offset_from_str("+00:00").is_ok());
+    let (offset, kind) = get_global_offset_with_kind().unwrap();
+    assert_eq!(offset, UtcOffset::UTC);
+    assert_eq!(kind, OffsetKind::Known);
+    let (ts, _) = get_local_timestamp_rfc3339().unwrap();
+    assert!(ts.ends_with("+00:00"), "ts: {}", ts);
+}
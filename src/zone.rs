@@ -0,0 +1,58 @@
+//! Resolution of UTC offsets from the system tz (IANA) database, so callers can pin a
+//! civil timezone instead of relying on the ambient system offset.
+
+use std::path::PathBuf;
+
+use time::{OffsetDateTime, UtcOffset};
+use tz::TimeZone;
+
+use crate::{Error, Result};
+
+const ZONEINFO_DIR: &str = "/usr/share/zoneinfo";
+
+/// Loads the named IANA zone (e.g. `America/New_York`) from the system tz database and
+/// returns the UTC offset in effect at `unix_time`.
+///
+/// The zone's transition table is binary-searched for the entry covering `unix_time`,
+/// falling back to its final/extra transition rule for instants beyond the last recorded
+/// transition, so the result is DST-correct rather than a single frozen offset.
+pub(crate) fn offset_for_zone_at(name: &str, unix_time: i64) -> Result<UtcOffset> {
+    let tz = load(name)?;
+    let local_time_type = tz
+        .find_local_time_type(unix_time)
+        .map_err(|e| Error::ZoneData(name.to_string(), e.to_string()))?;
+    Ok(UtcOffset::from_whole_seconds(local_time_type.ut_offset())?)
+}
+
+/// Loads the named IANA zone and returns the UTC offset in effect right now.
+pub(crate) fn offset_for_zone_now(name: &str) -> Result<UtcOffset> {
+    offset_for_zone_at(name, OffsetDateTime::now_utc().unix_timestamp())
+}
+
+fn load(name: &str) -> Result<TimeZone> {
+    let path = zoneinfo_path(name)?;
+    let bytes = std::fs::read(&path).map_err(|e| Error::ZoneFile(name.to_string(), e))?;
+    TimeZone::from_tz_data(&bytes).map_err(|e| Error::ZoneData(name.to_string(), e.to_string()))
+}
+
+/// Builds the on-disk path for a named zone, rejecting anything that isn't a plausible
+/// IANA zone name (e.g. `..` path traversal) before it ever reaches `std::fs::read`.
+fn zoneinfo_path(name: &str) -> Result<PathBuf> {
+    if !is_plausible_zone_name(name) {
+        return Err(Error::InvalidZoneName(name.to_string()));
+    }
+    Ok(PathBuf::from(ZONEINFO_DIR).join(name))
+}
+
+fn is_plausible_zone_name(name: &str) -> bool {
+    if name.is_empty() {
+        return false;
+    }
+    let valid_chars = name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '+' | '-' | '/'));
+    let valid_components = name
+        .split('/')
+        .all(|component| !component.is_empty() && component != "." && component != "..");
+    valid_chars && valid_components
+}